@@ -5,6 +5,24 @@
 pub struct RegressaoLinear {
     pub intercepto: f64,
     pub inclinacao: f64,
+    /// Coeficientes das variáveis independentes quando o modelo é ajustado
+    /// via `ajustar_multipla`. Fica vazio para um modelo ajustado via `ajustar`
+    /// (caso em que `inclinacao` já descreve o único regressor).
+    pub coeficientes: Vec<f64>,
+}
+
+/// Estatísticas de inferência dos coeficientes de uma regressão linear simples:
+/// erros-padrão, estatística t e intervalo de confiança de 95% para o
+/// intercepto e a inclinação.
+#[derive(Debug)]
+pub struct EstatisticasRegressao {
+    pub desvio_padrao_residual: f64,
+    pub erro_padrao_intercepto: f64,
+    pub erro_padrao_inclinacao: f64,
+    pub estatistica_t_intercepto: f64,
+    pub estatistica_t_inclinacao: f64,
+    pub intervalo_confianca_intercepto: (f64, f64),
+    pub intervalo_confianca_inclinacao: (f64, f64),
 }
 
 impl RegressaoLinear {
@@ -19,11 +37,11 @@ impl RegressaoLinear {
 
         let quantidade = periodos_x.len() as f64;
 
-        let soma_x: f64 = periodos_x.iter().sum();
-        let soma_y: f64 = valores_y.iter().sum();
+        let soma_x = soma_compensada(periodos_x.iter().copied());
+        let soma_y = soma_compensada(valores_y.iter().copied());
 
-        let soma_x_quadrado: f64 = periodos_x.iter().map(|v| v * v).sum();
-        let soma_xy: f64 = periodos_x.iter().zip(valores_y.iter()).map(|(xi, yi)| xi * yi).sum();
+        let soma_x_quadrado = soma_compensada(periodos_x.iter().map(|v| v * v));
+        let soma_xy = soma_compensada(periodos_x.iter().zip(valores_y.iter()).map(|(xi, yi)| xi * yi));
 
         let denominador = quantidade * soma_x_quadrado - soma_x * soma_x;
         if denominador == 0.0 {
@@ -36,43 +54,799 @@ impl RegressaoLinear {
         Ok(RegressaoLinear {
             intercepto,
             inclinacao,
+            coeficientes: Vec::new(),
         })
     }
 
-    /// Realiza a previsão de um valor futuro com base no modelo ajustado.
-    pub fn prever(&self, periodo_x: f64) -> f64 {
+    /// Ajusta uma regressão linear múltipla (OLS) de `valores_y` contra várias
+    /// variáveis independentes em `matriz_x` (uma linha por observação, uma
+    /// coluna por regressor). Resolve as equações normais `β = (XᵀX)⁻¹Xᵀy`,
+    /// adicionando internamente uma coluna de 1s para o intercepto.
+    ///
+    /// A inversão é feita por eliminação de Gauss-Jordan com pivoteamento
+    /// parcial sobre a matriz aumentada `[XᵀX | Xᵀy]`. Retorna `Err` se
+    /// `XᵀX` for singular (nenhum pivô utilizável for encontrado).
+    pub fn ajustar_multipla(matriz_x: &[Vec<f64>], valores_y: &[f64]) -> Result<Self, String> {
+        if matriz_x.len() != valores_y.len() || matriz_x.is_empty() {
+            return Err("Os vetores devem ter o mesmo tamanho e não podem ser vazios.".to_string());
+        }
+
+        let n = matriz_x.len();
+        let k = matriz_x[0].len();
+        if k == 0 || matriz_x.iter().any(|linha| linha.len() != k) {
+            return Err("Todas as linhas de `matriz_x` devem ter o mesmo número de colunas, maior que zero.".to_string());
+        }
+
+        // Número de parâmetros: intercepto + k regressores.
+        let p = k + 1;
+
+        // Monta X (com coluna de 1s para o intercepto) apenas conceitualmente,
+        // acumulando diretamente XᵀX e Xᵀy para evitar materializar X inteira.
+        let mut xtx = vec![vec![0.0_f64; p]; p];
+        let mut xty = vec![0.0_f64; p];
+
+        for linha in 0..n {
+            // Linha estendida de X: [1, x_1, ..., x_k].
+            let mut x_estendido = Vec::with_capacity(p);
+            x_estendido.push(1.0);
+            x_estendido.extend_from_slice(&matriz_x[linha]);
+
+            for i in 0..p {
+                xty[i] += x_estendido[i] * valores_y[linha];
+                for j in 0..p {
+                    xtx[i][j] += x_estendido[i] * x_estendido[j];
+                }
+            }
+        }
+
+        let beta = resolver_gauss_jordan(xtx, xty)?;
+
+        Ok(RegressaoLinear {
+            intercepto: beta[0],
+            inclinacao: beta.get(1).copied().unwrap_or(0.0),
+            coeficientes: beta[1..].to_vec(),
+        })
+    }
+
+    /// Ajusta os coeficientes de forma iterativa por gradiente descendente,
+    /// como alternativa à solução fechada de `ajustar`. Útil para séries
+    /// grandes e como base para futuras variantes multivariadas.
+    ///
+    /// Internamente, `periodos_x` é normalizado para `[0,1)` antes do
+    /// treino (evitando divergência com taxas de aprendizado moderadas) e os
+    /// coeficientes resultantes são convertidos de volta para a escala
+    /// original, de forma que `prever` continue funcionando normalmente.
+    ///
+    /// Retorna também o histórico do MSE calculado ao final de cada época,
+    /// útil para diagnosticar convergência (o chamador pode ignorá-lo).
+    pub fn ajustar_gradiente(
+        periodos_x: &[f64],
+        valores_y: &[f64],
+        taxa_aprendizado: f64,
+        epocas: usize,
+    ) -> Result<(Self, Vec<f64>), String> {
+        if periodos_x.len() != valores_y.len() || periodos_x.is_empty() {
+            return Err("Os vetores devem ter o mesmo tamanho e não podem ser vazios.".to_string());
+        }
+
+        let n = periodos_x.len() as f64;
+        let minimo_x = periodos_x.iter().cloned().fold(f64::INFINITY, f64::min);
+        let maximo_x = periodos_x.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let amplitude_x = maximo_x - minimo_x;
+
+        // Evita divisão por zero quando todos os períodos são iguais.
+        let x_normalizado: Vec<f64> = if amplitude_x == 0.0 {
+            periodos_x.iter().map(|_| 0.0).collect()
+        } else {
+            periodos_x.iter().map(|x| (x - minimo_x) / amplitude_x).collect()
+        };
+
+        let mut w0 = 0.0;
+        let mut w1 = 0.0;
+        let mut historico_mse = Vec::with_capacity(epocas);
+
+        for _ in 0..epocas {
+            let erros: Vec<f64> = x_normalizado.iter().zip(valores_y.iter())
+                .map(|(xi, yi)| (w0 + w1 * xi) - yi)
+                .collect();
+
+            let gradiente_w0: f64 = erros.iter().sum::<f64>() / n;
+            let gradiente_w1: f64 = erros.iter().zip(x_normalizado.iter())
+                .map(|(erro, xi)| erro * xi)
+                .sum::<f64>() / n;
+
+            w0 -= taxa_aprendizado * gradiente_w0;
+            w1 -= taxa_aprendizado * gradiente_w1;
+
+            let mse_epoca: f64 = x_normalizado.iter().zip(valores_y.iter())
+                .map(|(xi, yi)| ((w0 + w1 * xi) - yi).powi(2))
+                .sum::<f64>() / n;
+            historico_mse.push(mse_epoca);
+        }
+
+        // Converte os coeficientes de volta para a escala original de `periodos_x`:
+        // x_normalizado = (x - minimo_x) / amplitude_x.
+        let (inclinacao, intercepto) = if amplitude_x == 0.0 {
+            (0.0, w0)
+        } else {
+            let inclinacao = w1 / amplitude_x;
+            let intercepto = w0 - w1 * minimo_x / amplitude_x;
+            (inclinacao, intercepto)
+        };
+
+        Ok((
+            RegressaoLinear {
+                intercepto,
+                inclinacao,
+                coeficientes: Vec::new(),
+            },
+            historico_mse,
+        ))
+    }
+
+    /// Verifica que este modelo tem no máximo um regressor, isto é, que não foi
+    /// ajustado via `ajustar_multipla` com mais de uma variável independente.
+    /// `prever`/`r2`/`mse` só enxergam `inclinacao` (o primeiro coeficiente) e
+    /// ignorariam os demais silenciosamente, produzindo previsões erradas sem
+    /// sinalizar erro — por isso retornam `Err` aqui em vez disso.
+    fn checar_univariada(&self) -> Result<(), String> {
+        if self.coeficientes.len() > 1 {
+            return Err(format!(
+                "este modelo foi ajustado com {} regressores via `ajustar_multipla`; use `prever_multipla`/`r2_multipla`/`mse_multipla`, não `prever`/`r2`/`mse`.",
+                self.coeficientes.len()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Previsão de um modelo simples já sabido univariado, sem repetir a
+    /// checagem de `checar_univariada`. Uso interno, para chamadores que já
+    /// validaram a forma do modelo (ou o construíram eles mesmos via `ajustar`).
+    fn prever_sem_checagem(&self, periodo_x: f64) -> f64 {
         self.intercepto + self.inclinacao * periodo_x
     }
 
+    /// Realiza a previsão de um valor futuro com base no modelo ajustado.
+    /// Retorna `Err` se o modelo foi ajustado via `ajustar_multipla` com mais
+    /// de um regressor — use `prever_multipla` nesse caso.
+    pub fn prever(&self, periodo_x: f64) -> Result<f64, String> {
+        self.checar_univariada()?;
+        Ok(self.prever_sem_checagem(periodo_x))
+    }
+
+    /// Realiza a previsão de um modelo ajustado via `ajustar_multipla`,
+    /// recebendo um `features` com um valor por variável independente,
+    /// na mesma ordem usada em `matriz_x`.
+    pub fn prever_multipla(&self, features: &[f64]) -> Result<f64, String> {
+        if features.len() != self.coeficientes.len() {
+            return Err(format!(
+                "Esperado {} features, mas recebido {}.",
+                self.coeficientes.len(),
+                features.len()
+            ));
+        }
+
+        let soma_regressores: f64 = self.coeficientes.iter().zip(features.iter())
+            .map(|(coef, x)| coef * x)
+            .sum();
+
+        Ok(self.intercepto + soma_regressores)
+    }
+
     /// Calcula o coeficiente de determinação R²,
     /// indicando o quão bem a linha ajustada representa os dados observados.
-    pub fn r2(&self, periodos_x: &[f64], valores_y: &[f64]) -> f64 {
-        let media_y: f64 = valores_y.iter().sum::<f64>() / valores_y.len() as f64;
+    /// Retorna `Err` se o modelo foi ajustado via `ajustar_multipla` com mais
+    /// de um regressor — use `r2_multipla` nesse caso.
+    pub fn r2(&self, periodos_x: &[f64], valores_y: &[f64]) -> Result<f64, String> {
+        self.checar_univariada()?;
+        let media_y = soma_compensada(valores_y.iter().copied()) / valores_y.len() as f64;
 
-        let soma_total: f64 = valores_y.iter().map(|yi| (yi - media_y).powi(2)).sum();
-        let soma_residual: f64 = periodos_x.iter().zip(valores_y.iter())
-            .map(|(xi, yi)| {
-                let y_estimado = self.prever(*xi);
-                (yi - y_estimado).powi(2)
-            })
-            .sum();
+        let soma_total = soma_compensada(valores_y.iter().map(|yi| (yi - media_y).powi(2)));
+        let soma_residual = soma_compensada(
+            periodos_x.iter().zip(valores_y.iter())
+                .map(|(xi, yi)| {
+                    let y_estimado = self.prever_sem_checagem(*xi);
+                    (yi - y_estimado).powi(2)
+                })
+        );
 
-        1.0 - (soma_residual / soma_total)
+        Ok(1.0 - (soma_residual / soma_total))
     }
 
     /// Calcula o Erro Quadrático Médio (MSE) do modelo ajustado.
-    pub fn mse(&self, periodos_x: &[f64], valores_y: &[f64]) -> f64 {
+    /// Retorna `Err` se o modelo foi ajustado via `ajustar_multipla` com mais
+    /// de um regressor — use `mse_multipla` nesse caso.
+    pub fn mse(&self, periodos_x: &[f64], valores_y: &[f64]) -> Result<f64, String> {
+        self.checar_univariada()?;
         let quantidade = valores_y.len() as f64;
-        let erro_total: f64 = periodos_x.iter().zip(valores_y.iter())
-            .map(|(xi, yi)| {
-                let y_estimado = self.prever(*xi);
-                (yi - y_estimado).powi(2)
-            })
+        let erro_total = soma_compensada(
+            periodos_x.iter().zip(valores_y.iter())
+                .map(|(xi, yi)| {
+                    let y_estimado = self.prever_sem_checagem(*xi);
+                    (yi - y_estimado).powi(2)
+                })
+        );
+        Ok(erro_total / quantidade)
+    }
+
+    /// Calcula erros-padrão, estatística t e intervalo de confiança de 95%
+    /// para o intercepto e a inclinação de um modelo ajustado via `ajustar`.
+    ///
+    /// Usa o desvio-padrão residual `s² = SQR/(n-2)` e o valor crítico da
+    /// distribuição t com `n-2` graus de liberdade para montar os intervalos.
+    /// Requer pelo menos 3 observações (`n-2` graus de liberdade positivos).
+    pub fn estatisticas(&self, periodos_x: &[f64], valores_y: &[f64]) -> Result<EstatisticasRegressao, String> {
+        self.checar_univariada()?;
+        let quantidade = periodos_x.len();
+        if quantidade != valores_y.len() || quantidade < 3 {
+            return Err("São necessárias ao menos 3 observações para estimar erros-padrão.".to_string());
+        }
+        let n = quantidade as f64;
+        let graus_liberdade = n - 2.0;
+
+        let soma_quadratica_residual: f64 = periodos_x.iter().zip(valores_y.iter())
+            .map(|(xi, yi)| (yi - self.prever_sem_checagem(*xi)).powi(2))
             .sum();
-        erro_total / quantidade
+        let s_quadrado = soma_quadratica_residual / graus_liberdade;
+
+        let media_x: f64 = periodos_x.iter().sum::<f64>() / n;
+        let soma_desvios_x_quadrado: f64 = periodos_x.iter().map(|xi| (xi - media_x).powi(2)).sum();
+        if soma_desvios_x_quadrado == 0.0 {
+            return Err("Variância nula em `periodos_x`: não é possível estimar os erros-padrão.".to_string());
+        }
+
+        let erro_padrao_inclinacao = (s_quadrado / soma_desvios_x_quadrado).sqrt();
+        let erro_padrao_intercepto = (s_quadrado * (1.0 / n + media_x.powi(2) / soma_desvios_x_quadrado)).sqrt();
+
+        let estatistica_t_intercepto = self.intercepto / erro_padrao_intercepto;
+        let estatistica_t_inclinacao = self.inclinacao / erro_padrao_inclinacao;
+
+        let t_critico = valor_critico_t_95(graus_liberdade);
+        let intervalo_confianca_intercepto = (
+            self.intercepto - t_critico * erro_padrao_intercepto,
+            self.intercepto + t_critico * erro_padrao_intercepto,
+        );
+        let intervalo_confianca_inclinacao = (
+            self.inclinacao - t_critico * erro_padrao_inclinacao,
+            self.inclinacao + t_critico * erro_padrao_inclinacao,
+        );
+
+        Ok(EstatisticasRegressao {
+            desvio_padrao_residual: s_quadrado.sqrt(),
+            erro_padrao_intercepto,
+            erro_padrao_inclinacao,
+            estatistica_t_intercepto,
+            estatistica_t_inclinacao,
+            intervalo_confianca_intercepto,
+            intervalo_confianca_inclinacao,
+        })
+    }
+
+    /// Calcula o R² para um modelo ajustado via `ajustar_multipla`.
+    pub fn r2_multipla(&self, matriz_x: &[Vec<f64>], valores_y: &[f64]) -> Result<f64, String> {
+        let media_y: f64 = valores_y.iter().sum::<f64>() / valores_y.len() as f64;
+        let soma_total: f64 = valores_y.iter().map(|yi| (yi - media_y).powi(2)).sum();
+
+        let mut soma_residual = 0.0;
+        for (features, yi) in matriz_x.iter().zip(valores_y.iter()) {
+            let y_estimado = self.prever_multipla(features)?;
+            soma_residual += (yi - y_estimado).powi(2);
+        }
+
+        Ok(1.0 - (soma_residual / soma_total))
+    }
+
+    /// Calcula o Erro Quadrático Médio (MSE) para um modelo ajustado via `ajustar_multipla`.
+    pub fn mse_multipla(&self, matriz_x: &[Vec<f64>], valores_y: &[f64]) -> Result<f64, String> {
+        let quantidade = valores_y.len() as f64;
+        let mut erro_total = 0.0;
+        for (features, yi) in matriz_x.iter().zip(valores_y.iter()) {
+            let y_estimado = self.prever_multipla(features)?;
+            erro_total += (yi - y_estimado).powi(2);
+        }
+
+        Ok(erro_total / quantidade)
+    }
+
+    /// Ajusta um modelo ARMA(p,q) sobre os resíduos `r_t = y_t - prever(x_t)`
+    /// deste modelo já ajustado, para capturar a autocorrelação que a
+    /// tendência linear não explica. Exige que `periodos_x` esteja em ordem
+    /// estritamente crescente e uniformemente espaçado — `prever_horizonte`
+    /// extrapola com um único passo constante a partir do último período, e
+    /// um espaçamento irregular faria essa extrapolação usar silenciosamente
+    /// o passo errado. Retorna `Err` caso o espaçamento não seja uniforme.
+    ///
+    /// A parte AR é estimada pelas equações de Yule-Walker a partir da
+    /// autocovariância amostral dos resíduos (reaproveitando
+    /// `resolver_gauss_jordan` sobre o sistema de Toeplitz). A parte MA é
+    /// então ajustada iterativamente sobre as inovações (resíduos da parte
+    /// AR), minimizando a soma dos quadrados das inovações por descida de
+    /// gradiente.
+    ///
+    /// Retorna `PrevisorArma`, que combina este modelo com o ARMA ajustado
+    /// para produzir previsões multi-passo via `prever_horizonte`.
+    pub fn ajustar_arma_residuos(
+        &self,
+        periodos_x: &[f64],
+        valores_y: &[f64],
+        ordem_ar: usize,
+        ordem_ma: usize,
+    ) -> Result<PrevisorArma, String> {
+        self.checar_univariada()?;
+        if periodos_x.len() != valores_y.len() || periodos_x.len() < 2 {
+            return Err("Os vetores devem ter o mesmo tamanho e ao menos 2 observações.".to_string());
+        }
+        let n = periodos_x.len();
+        if ordem_ar >= n || ordem_ma >= n {
+            return Err("As ordens AR e MA devem ser menores que o número de observações.".to_string());
+        }
+
+        let passo = passo_uniforme(periodos_x)?;
+
+        let residuos: Vec<f64> = periodos_x.iter().zip(valores_y.iter())
+            .map(|(xi, yi)| yi - self.prever_sem_checagem(*xi))
+            .collect();
+
+        let arma = ajustar_arma(&residuos, ordem_ar, ordem_ma)?;
+        let inovacoes = inovacoes_arma(&residuos, &arma);
+
+        Ok(PrevisorArma {
+            regressao: RegressaoLinear {
+                intercepto: self.intercepto,
+                inclinacao: self.inclinacao,
+                coeficientes: self.coeficientes.clone(),
+            },
+            arma,
+            ultimo_periodo: periodos_x[n - 1],
+            passo,
+            residuos,
+            inovacoes,
+        })
+    }
+}
+
+/// Verifica que `periodos_x` (com ao menos 2 elementos) está em ordem
+/// estritamente crescente e uniformemente espaçado, retornando o passo
+/// comum entre períodos consecutivos. Retorna `Err` caso contrário.
+fn passo_uniforme(periodos_x: &[f64]) -> Result<f64, String> {
+    let passo = periodos_x[1] - periodos_x[0];
+    if passo <= 0.0 {
+        return Err("`periodos_x` deve estar em ordem estritamente crescente.".to_string());
+    }
+
+    for janela in periodos_x.windows(2) {
+        let passo_atual = janela[1] - janela[0];
+        if (passo_atual - passo).abs() > 1e-9 * passo.abs().max(1.0) {
+            return Err("`periodos_x` deve ser uniformemente espaçado.".to_string());
+        }
+    }
+
+    Ok(passo)
+}
+
+/// Modelo ARMA(p,q) ajustado sobre uma série de resíduos:
+/// `r_t = c + Σφᵢ·r_{t-i} + Σθⱼ·ε_{t-j} + ε_t`.
+#[derive(Debug, Clone)]
+pub struct ModeloArma {
+    pub c: f64,
+    pub phi: Vec<f64>,
+    pub theta: Vec<f64>,
+}
+
+/// Combina uma `RegressaoLinear` (tendência determinística) com um
+/// `ModeloArma` ajustado sobre os resíduos, permitindo previsões multi-passo
+/// que somam a tendência extrapolada à previsão ARMA dos resíduos.
+pub struct PrevisorArma {
+    regressao: RegressaoLinear,
+    arma: ModeloArma,
+    ultimo_periodo: f64,
+    passo: f64,
+    residuos: Vec<f64>,
+    inovacoes: Vec<f64>,
+}
+
+impl PrevisorArma {
+    /// Prevê `h` passos à frente, combinando a tendência linear extrapolada
+    /// com a previsão ARMA dos resíduos. Para `eps` futuros (desconhecidos),
+    /// usa o valor esperado de um processo de ruído branco: zero.
+    pub fn prever_horizonte(&self, h: usize) -> Vec<f64> {
+        let p = self.arma.phi.len();
+        let q = self.arma.theta.len();
+
+        // Históricos estendidos com os valores previstos, para alimentar os
+        // termos autorregressivos e de média móvel dos passos seguintes.
+        let mut residuos_estendidos = self.residuos.clone();
+        let mut inovacoes_estendidas = self.inovacoes.clone();
+
+        let mut previsoes = Vec::with_capacity(h);
+
+        for passo in 1..=h {
+            let m = residuos_estendidos.len();
+
+            let termo_ar: f64 = (0..p)
+                .map(|i| self.arma.phi[i] * residuos_estendidos[m - 1 - i])
+                .sum();
+            let termo_ma: f64 = (0..q)
+                .filter(|&j| j < inovacoes_estendidas.len())
+                .map(|j| self.arma.theta[j] * inovacoes_estendidas[inovacoes_estendidas.len() - 1 - j])
+                .sum();
+
+            let residuo_previsto = self.arma.c + termo_ar + termo_ma;
+
+            let periodo_futuro = self.ultimo_periodo + self.passo * passo as f64;
+            previsoes.push(self.regressao.prever_sem_checagem(periodo_futuro) + residuo_previsto);
+
+            residuos_estendidos.push(residuo_previsto);
+            // A inovação esperada para um passo futuro é zero (ruído branco).
+            inovacoes_estendidas.push(0.0);
+        }
+
+        previsoes
+    }
+}
+
+/// Ajusta um ARMA(p,q) sobre `serie`: a parte AR via Yule-Walker e a parte MA
+/// por descida de gradiente sobre as inovações da parte AR.
+fn ajustar_arma(serie: &[f64], ordem_ar: usize, ordem_ma: usize) -> Result<ModeloArma, String> {
+    let n = serie.len();
+    let media: f64 = soma_compensada(serie.iter().copied()) / n as f64;
+    let desviado: Vec<f64> = serie.iter().map(|v| v - media).collect();
+
+    let phi = if ordem_ar == 0 {
+        Vec::new()
+    } else {
+        let autocovariancia = |defasagem: usize| -> f64 {
+            soma_compensada((0..n - defasagem).map(|t| desviado[t] * desviado[t + defasagem])) / n as f64
+        };
+
+        let gamma: Vec<f64> = (0..=ordem_ar).map(autocovariancia).collect();
+        if gamma[0].abs() < 1e-12 {
+            return Err("Variância nula nos resíduos: não é possível ajustar a parte AR.".to_string());
+        }
+
+        // Sistema de Yule-Walker: matriz de Toeplitz das autocovariâncias.
+        let mut matriz = vec![vec![0.0_f64; ordem_ar]; ordem_ar];
+        for i in 0..ordem_ar {
+            for j in 0..ordem_ar {
+                matriz[i][j] = gamma[(i as isize - j as isize).unsigned_abs()];
+            }
+        }
+        let vetor: Vec<f64> = (1..=ordem_ar).map(|k| gamma[k]).collect();
+
+        resolver_gauss_jordan(matriz, vetor)?
+    };
+
+    let c = media * (1.0 - phi.iter().sum::<f64>());
+
+    // Inovações da parte AR: resíduo de prever `desviado[t]` pelos seus próprios lags.
+    let inovacoes_ar: Vec<f64> = (phi.len()..n)
+        .map(|t| {
+            let previsto: f64 = phi.iter().enumerate().map(|(i, coef)| coef * desviado[t - 1 - i]).sum();
+            desviado[t] - previsto
+        })
+        .collect();
+
+    let theta = if ordem_ma == 0 || inovacoes_ar.is_empty() {
+        Vec::new()
+    } else {
+        ajustar_ma_por_gradiente(&inovacoes_ar, ordem_ma, 0.01, 200)
+    };
+
+    Ok(ModeloArma { c, phi, theta })
+}
+
+/// Ajusta os coeficientes MA(q) iterativamente por descida de gradiente,
+/// minimizando a soma dos quadrados das inovações reconstruídas
+/// `ε_t = u_t - Σθⱼ·ε_{t-j}`, onde `u_t` são as inovações da parte AR.
+fn ajustar_ma_por_gradiente(u: &[f64], ordem_ma: usize, taxa_aprendizado: f64, epocas: usize) -> Vec<f64> {
+    let m = u.len();
+    let mut theta = vec![0.0_f64; ordem_ma];
+
+    for _ in 0..epocas {
+        let mut eps = vec![0.0_f64; m];
+        for t in 0..m {
+            let lags_disponiveis = ordem_ma.min(t);
+            let previsto: f64 = (0..lags_disponiveis).map(|j| theta[j] * eps[t - 1 - j]).sum();
+            eps[t] = u[t] - previsto;
+        }
+
+        let mut gradiente = vec![0.0_f64; ordem_ma];
+        for t in 0..m {
+            let lags_disponiveis = ordem_ma.min(t);
+            for j in 0..lags_disponiveis {
+                gradiente[j] += -2.0 * eps[t] * eps[t - 1 - j];
+            }
+        }
+
+        for j in 0..ordem_ma {
+            theta[j] -= taxa_aprendizado * gradiente[j] / m as f64;
+        }
+    }
+
+    theta
+}
+
+/// Reconstrói a série de inovações finais (`ε_t`) do modelo ARMA ajustado,
+/// usada como histórico para alimentar a parte MA das previsões futuras.
+fn inovacoes_arma(residuos: &[f64], arma: &ModeloArma) -> Vec<f64> {
+    let p = arma.phi.len();
+    let q = arma.theta.len();
+    let n = residuos.len();
+
+    let mut inovacoes = Vec::with_capacity(n);
+    for t in 0..n {
+        if t < p {
+            inovacoes.push(0.0);
+            continue;
+        }
+        let termo_ar: f64 = (0..p).map(|i| arma.phi[i] * residuos[t - 1 - i]).sum();
+        let termo_ma: f64 = (0..q)
+            .filter(|&j| t > j && t - 1 - j < inovacoes.len())
+            .map(|j| arma.theta[j] * inovacoes[t - 1 - j])
+            .sum();
+        inovacoes.push(residuos[t] - arma.c - termo_ar - termo_ma);
+    }
+
+    inovacoes
+}
+
+/// Julga se `valores_y` (contra `periodos_x`) é bem descrita por uma reta,
+/// como validação antes de confiar em `RegressaoLinear` para previsões.
+///
+/// Escala `periodos_x` linearmente para `[0,1)`, ajusta a regressão e exige
+/// que tanto o RMSE quanto o erro absoluto máximo dos resíduos fiquem dentro
+/// de `tolerancia`, e que o intercepto não seja excessivamente negativo frente
+/// à magnitude típica de `valores_y`. A primeira rodada sempre avalia a série
+/// inteira; rodadas seguintes descartam um prefixo cada vez maior, como
+/// heurística contra ruído concentrado bem no início da amostra — mas o
+/// descarte é limitado a `FRACAO_MAXIMA_DESCARTADA` do total de pontos, para
+/// que uma rodada não possa "passar" apenas por eliminar a maior parte da
+/// série (o que deixaria a cauda de curvas que se aplainam — logarítmicas,
+/// raiz quadrada, saturantes — parecer linear mesmo quando a série inteira
+/// claramente não é).
+pub fn parece_linear(periodos_x: &[f64], valores_y: &[f64], tolerancia: f64) -> bool {
+    const RODADAS: usize = 3;
+    const MINIMO_PONTOS: usize = 3;
+    const FRACAO_MAXIMA_DESCARTADA: f64 = 0.2;
+
+    if periodos_x.len() != valores_y.len() || periodos_x.len() < MINIMO_PONTOS {
+        return false;
+    }
+    let n = periodos_x.len();
+    let descarte_maximo = ((n as f64 * FRACAO_MAXIMA_DESCARTADA) as usize).min(n - MINIMO_PONTOS);
+
+    for rodada in 0..RODADAS {
+        let inicio = if RODADAS <= 1 {
+            0
+        } else {
+            (rodada * descarte_maximo / (RODADAS - 1)).min(descarte_maximo)
+        };
+        if avaliar_linearidade(&periodos_x[inicio..], &valores_y[inicio..], tolerancia) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Avalia uma única rodada de `parece_linear` sobre a janela `(x, y)` recebida.
+fn avaliar_linearidade(x: &[f64], y: &[f64], tolerancia: f64) -> bool {
+    let minimo_x = x.iter().cloned().fold(f64::INFINITY, f64::min);
+    let maximo_x = x.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let amplitude_x = maximo_x - minimo_x;
+
+    let x_escalado: Vec<f64> = if amplitude_x == 0.0 {
+        x.iter().map(|_| 0.0).collect()
+    } else {
+        x.iter().map(|xi| (xi - minimo_x) / amplitude_x).collect()
+    };
+
+    let modelo = match RegressaoLinear::ajustar(&x_escalado, y) {
+        Ok(modelo) => modelo,
+        Err(_) => return false,
+    };
+
+    let residuos: Vec<f64> = x_escalado.iter().zip(y.iter())
+        .map(|(xi, yi)| yi - modelo.prever_sem_checagem(*xi))
+        .collect();
+
+    let n = residuos.len() as f64;
+    let rmse = (soma_compensada(residuos.iter().map(|r| r * r)) / n).sqrt();
+    let erro_maximo = residuos.iter().fold(0.0_f64, |maior, r| maior.max(r.abs()));
+
+    let media_absoluta_y = soma_compensada(y.iter().map(|v| v.abs())) / n;
+    let intercepto_razoavel = modelo.intercepto > -2.0 * media_absoluta_y.max(1.0);
+
+    rmse <= tolerancia && erro_maximo <= tolerancia && intercepto_razoavel
+}
+
+/// Estima o expoente de Hurst de `serie` pela análise R/S (rescaled range),
+/// que indica se a série é persistente/com tendência (`H > 0.5`),
+/// anti-persistente (`H < 0.5`) ou aleatória (`H ≈ 0.5`).
+///
+/// Para cada tamanho de janela (metades sucessivas de `serie`, até ~8
+/// pontos), calcula o `R/S` médio entre as janelas daquele tamanho: subtrai a
+/// média da janela, acumula os desvios, divide o range (`max - min`) da série
+/// acumulada pelo desvio-padrão da janela. Ajusta então uma reta em escala
+/// log-log sobre `(ln tamanho, ln R/S)` reaproveitando `RegressaoLinear::ajustar`
+/// — a inclinação resultante é o expoente de Hurst.
+pub fn expoente_hurst(serie: &[f64]) -> Result<f64, String> {
+    const TAMANHO_MINIMO: usize = 8;
+
+    let n = serie.len();
+    if n < TAMANHO_MINIMO * 2 {
+        return Err(format!(
+            "São necessários ao menos {} pontos para estimar o expoente de Hurst.",
+            TAMANHO_MINIMO * 2
+        ));
+    }
+
+    let mut tamanhos = Vec::new();
+    let mut tamanho = n;
+    while tamanho >= TAMANHO_MINIMO {
+        tamanhos.push(tamanho);
+        tamanho /= 2;
+    }
+
+    let mut ln_tamanhos = Vec::new();
+    let mut ln_rs_medios = Vec::new();
+
+    for &tam in &tamanhos {
+        let quantidade_janelas = n / tam;
+        let mut soma_rs = 0.0;
+        let mut contagem = 0usize;
+
+        for indice_janela in 0..quantidade_janelas {
+            let inicio = indice_janela * tam;
+            if let Some(rs) = calcular_rs(&serie[inicio..inicio + tam]) {
+                soma_rs += rs;
+                contagem += 1;
+            }
+        }
+
+        if contagem > 0 {
+            let rs_medio = soma_rs / contagem as f64;
+            if rs_medio > 0.0 {
+                ln_tamanhos.push((tam as f64).ln());
+                ln_rs_medios.push(rs_medio.ln());
+            }
+        }
+    }
+
+    if ln_tamanhos.len() < 2 {
+        return Err("Pontos insuficientes de R/S para ajustar a reta log-log.".to_string());
+    }
+
+    let modelo = RegressaoLinear::ajustar(&ln_tamanhos, &ln_rs_medios)?;
+    Ok(modelo.inclinacao)
+}
+
+/// Calcula o estatístico `R/S` (rescaled range) de uma única janela da série.
+/// Retorna `None` quando o desvio-padrão da janela é nulo (janela constante).
+fn calcular_rs(janela: &[f64]) -> Option<f64> {
+    let n = janela.len() as f64;
+    let media = soma_compensada(janela.iter().copied()) / n;
+    let desvios: Vec<f64> = janela.iter().map(|v| v - media).collect();
+
+    let mut acumulado = 0.0;
+    let mut minimo = 0.0_f64;
+    let mut maximo = 0.0_f64;
+    for d in &desvios {
+        acumulado += d;
+        minimo = minimo.min(acumulado);
+        maximo = maximo.max(acumulado);
+    }
+    let amplitude = maximo - minimo;
+
+    let variancia = soma_compensada(desvios.iter().map(|d| d * d)) / n;
+    let desvio_padrao = variancia.sqrt();
+
+    if desvio_padrao == 0.0 {
+        None
+    } else {
+        Some(amplitude / desvio_padrao)
     }
 }
 
+/// Soma os valores de `iter` usando o algoritmo de Kahan-Neumaier, que
+/// compensa o erro de arredondamento acumulado em somas ingênuas —
+/// importante para séries longas ou com valores de grande magnitude
+/// (ex.: timestamps), onde `.sum()` cru produz inclinações e R² imprecisos.
+fn soma_compensada(iter: impl Iterator<Item = f64>) -> f64 {
+    let mut soma = 0.0;
+    let mut compensacao = 0.0;
+
+    for x in iter {
+        let t = soma + x;
+        compensacao += if soma.abs() >= x.abs() {
+            (soma - t) + x
+        } else {
+            (x - t) + soma
+        };
+        soma = t;
+    }
+
+    soma + compensacao
+}
+
+/// Valor crítico bicaudal de 95% (`alpha = 0.05`) da distribuição t de Student
+/// para `graus_liberdade` graus de liberdade.
+///
+/// Para `df <= 2`, a expansão de Cornish-Fisher usada abaixo extrapola a
+/// série assintótica para fora da sua região confiável (ex.: ~24% de erro em
+/// `df=1`), o que inflaria artificialmente a significância reportada para
+/// séries pequenas — exatamente o caso mais comum de uso desta função. Por
+/// isso esses graus de liberdade usam valores tabelados exatos; a partir de
+/// `df=3` a aproximação já é precisa o bastante.
+fn valor_critico_t_95(graus_liberdade: f64) -> f64 {
+    match graus_liberdade.round() as i64 {
+        1 => return 12.706,
+        2 => return 4.303,
+        _ => {}
+    }
+
+    const Z: f64 = 1.959964;
+    let v = graus_liberdade;
+
+    let g1 = (Z.powi(3) + Z) / 4.0;
+    let g2 = (5.0 * Z.powi(5) + 16.0 * Z.powi(3) + 3.0 * Z) / 96.0;
+    let g3 = (3.0 * Z.powi(7) + 19.0 * Z.powi(5) + 17.0 * Z.powi(3) - 15.0 * Z) / 384.0;
+
+    Z + g1 / v + g2 / v.powi(2) + g3 / v.powi(3)
+}
+
+/// Resolve o sistema linear `a·x = b` por eliminação de Gauss-Jordan com
+/// pivoteamento parcial. `a` é uma matriz quadrada `p×p` e `b` um vetor de
+/// tamanho `p`; retorna `Err` se não houver pivô utilizável (matriz singular).
+fn resolver_gauss_jordan(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Result<Vec<f64>, String> {
+    let p = b.len();
+    const LIMIAR_PIVO: f64 = 1e-12;
+
+    for coluna in 0..p {
+        // Pivoteamento parcial: escolhe a linha com maior valor absoluto na coluna atual.
+        let linha_pivo = (coluna..p)
+            .max_by(|&i, &j| a[i][coluna].abs().partial_cmp(&a[j][coluna].abs()).unwrap())
+            .unwrap();
+
+        if a[linha_pivo][coluna].abs() < LIMIAR_PIVO {
+            return Err("Matriz singular: não foi possível encontrar um pivô válido.".to_string());
+        }
+
+        a.swap(coluna, linha_pivo);
+        b.swap(coluna, linha_pivo);
+
+        let pivo = a[coluna][coluna];
+        for valor in a[coluna].iter_mut() {
+            *valor /= pivo;
+        }
+        b[coluna] /= pivo;
+
+        for linha in 0..p {
+            if linha == coluna {
+                continue;
+            }
+            let fator = a[linha][coluna];
+            if fator == 0.0 {
+                continue;
+            }
+
+            let (linha_alvo, linha_pivo) = if linha < coluna {
+                let (esquerda, direita) = a.split_at_mut(coluna);
+                (&mut esquerda[linha], &direita[0])
+            } else {
+                let (esquerda, direita) = a.split_at_mut(linha);
+                (&mut direita[0], &esquerda[coluna])
+            };
+            for (valor, &valor_pivo) in linha_alvo.iter_mut().zip(linha_pivo.iter()) {
+                *valor -= fator * valor_pivo;
+            }
+            b[linha] -= fator * b[coluna];
+        }
+    }
+
+    Ok(b)
+}
+
 fn main() {
     // Exemplo de uso da regressão linear
 
@@ -85,12 +859,14 @@ fn main() {
         .expect("Erro no cálculo da regressão");
 
     println!("Modelo ajustado: {:?}", modelo);
-    println!("Coeficiente de determinação R²: {:.4}", modelo.r2(&periodos_x, &valores_y));
-    println!("Erro quadrático médio (MSE): {:.4}", modelo.mse(&periodos_x, &valores_y));
+    let r2 = modelo.r2(&periodos_x, &valores_y).expect("Erro ao calcular R²");
+    let mse = modelo.mse(&periodos_x, &valores_y).expect("Erro ao calcular MSE");
+    println!("Coeficiente de determinação R²: {:.4}", r2);
+    println!("Erro quadrático médio (MSE): {:.4}", mse);
 
     // Fazendo previsões para os períodos futuros 5, 6 e 7
     for periodo in 5..8 {
-        let previsao = modelo.prever(periodo as f64);
+        let previsao = modelo.prever(periodo as f64).expect("Erro ao prever");
         println!("Previsão para t = {}: {:.4}", periodo, previsao);
     }
 }
@@ -119,7 +895,7 @@ mod tests {
 
         let modelo = RegressaoLinear::ajustar(&periodos_x, &valores_y).unwrap();
 
-        let previsao = modelo.prever(4.0);
+        let previsao = modelo.prever(4.0).unwrap();
         assert!((previsao - 9.0).abs() < 1e-6);
     }
 
@@ -130,7 +906,254 @@ mod tests {
 
         let modelo = RegressaoLinear::ajustar(&periodos_x, &valores_y).unwrap();
 
-        let r2 = modelo.r2(&periodos_x, &valores_y);
+        let r2 = modelo.r2(&periodos_x, &valores_y).unwrap();
         assert!((r2 - 1.0).abs() < 1e-6);
     }
+
+    #[test]
+    fn testa_ajustar_multipla_coeficientes() {
+        // y = 1 + 2*x1 + 3*x2, sem ruído.
+        let matriz_x = vec![
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![0.0, 1.0],
+            vec![1.0, 1.0],
+            vec![2.0, 1.0],
+        ];
+        let valores_y: Vec<f64> = matriz_x.iter()
+            .map(|linha| 1.0 + 2.0 * linha[0] + 3.0 * linha[1])
+            .collect();
+
+        let modelo = RegressaoLinear::ajustar_multipla(&matriz_x, &valores_y).unwrap();
+
+        assert!((modelo.intercepto - 1.0).abs() < 1e-6);
+        assert!((modelo.coeficientes[0] - 2.0).abs() < 1e-6);
+        assert!((modelo.coeficientes[1] - 3.0).abs() < 1e-6);
+
+        let r2 = modelo.r2_multipla(&matriz_x, &valores_y).unwrap();
+        assert!((r2 - 1.0).abs() < 1e-6);
+
+        let previsao = modelo.prever_multipla(&[3.0, 4.0]).unwrap();
+        assert!((previsao - (1.0 + 2.0 * 3.0 + 3.0 * 4.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn testa_prever_retorna_erro_para_modelo_multivariado() {
+        // `prever` só enxerga `inclinacao` (o primeiro coeficiente) e ignoraria
+        // os demais regressores silenciosamente; deve retornar `Err` em vez disso.
+        let matriz_x = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0], vec![1.0, 1.0]];
+        let valores_y: Vec<f64> = matriz_x.iter().map(|l| 1.0 + 2.0 * l[0] + 3.0 * l[1]).collect();
+
+        let modelo = RegressaoLinear::ajustar_multipla(&matriz_x, &valores_y).unwrap();
+        assert!(modelo.prever(3.0).is_err());
+        assert!(modelo.r2(&[0.0, 1.0], &[1.0, 2.0]).is_err());
+        assert!(modelo.mse(&[0.0, 1.0], &[1.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn testa_ajustar_multipla_matriz_singular() {
+        // x2 é sempre o dobro de x1: colinearidade perfeita, XᵀX é singular.
+        let matriz_x = vec![
+            vec![1.0, 2.0],
+            vec![2.0, 4.0],
+            vec![3.0, 6.0],
+        ];
+        let valores_y = [1.0, 2.0, 3.0];
+
+        let resultado = RegressaoLinear::ajustar_multipla(&matriz_x, &valores_y);
+        assert!(resultado.is_err());
+    }
+
+    #[test]
+    fn testa_ajustar_multipla_tamanhos_incompativeis() {
+        let matriz_x = vec![vec![1.0], vec![2.0]];
+        let valores_y = [1.0];
+
+        let resultado = RegressaoLinear::ajustar_multipla(&matriz_x, &valores_y);
+        assert!(resultado.is_err());
+    }
+
+    #[test]
+    fn testa_estatisticas_ajuste_perfeito() {
+        // Ajuste perfeito (sem ruído): erros-padrão e estatística t devem ser nulos/infinitos
+        // de forma consistente, mas o desvio-padrão residual deve ser ~0.
+        let valores_y = [2.0, 4.0, 6.0, 8.0, 10.0];
+        let periodos_x: Vec<f64> = (0..valores_y.len()).map(|v| v as f64).collect();
+
+        let modelo = RegressaoLinear::ajustar(&periodos_x, &valores_y).unwrap();
+        let estatisticas = modelo.estatisticas(&periodos_x, &valores_y).unwrap();
+
+        assert!(estatisticas.desvio_padrao_residual.abs() < 1e-6);
+        assert!(estatisticas.erro_padrao_inclinacao.abs() < 1e-6);
+    }
+
+    #[test]
+    fn testa_estatisticas_intervalo_contem_coeficiente() {
+        // Com ruído, o coeficiente verdadeiro deve cair dentro do IC de 95%.
+        let periodos_x: Vec<f64> = (0..10).map(|v| v as f64).collect();
+        let valores_y: Vec<f64> = periodos_x.iter()
+            .enumerate()
+            .map(|(i, x)| 3.0 + 2.0 * x + if i % 2 == 0 { 0.3 } else { -0.3 })
+            .collect();
+
+        let modelo = RegressaoLinear::ajustar(&periodos_x, &valores_y).unwrap();
+        let estatisticas = modelo.estatisticas(&periodos_x, &valores_y).unwrap();
+
+        let (ic_min, ic_max) = estatisticas.intervalo_confianca_inclinacao;
+        assert!(ic_min < modelo.inclinacao && modelo.inclinacao < ic_max);
+        assert!(ic_min < 2.0 && 2.0 < ic_max);
+    }
+
+    #[test]
+    fn testa_estatisticas_poucas_observacoes() {
+        let valores_y = [2.0, 4.0];
+        let periodos_x = [0.0, 1.0];
+
+        let modelo = RegressaoLinear::ajustar(&periodos_x, &valores_y).unwrap();
+        assert!(modelo.estatisticas(&periodos_x, &valores_y).is_err());
+    }
+
+    #[test]
+    fn testa_estatisticas_df_1_usa_valor_critico_exato() {
+        // n=3 -> graus_liberdade=1, onde a expansão de Cornish-Fisher extrapola
+        // mal; o IC deve usar o valor tabelado exato (12.706), não a aproximação.
+        let valores_y = [2.2, 3.9, 6.1];
+        let periodos_x = [0.0, 1.0, 2.0];
+
+        let modelo = RegressaoLinear::ajustar(&periodos_x, &valores_y).unwrap();
+        let estatisticas = modelo.estatisticas(&periodos_x, &valores_y).unwrap();
+
+        let amplitude_ic = estatisticas.intervalo_confianca_inclinacao.1 - estatisticas.intervalo_confianca_inclinacao.0;
+        let amplitude_esperada = 2.0 * 12.706 * estatisticas.erro_padrao_inclinacao;
+        assert!((amplitude_ic - amplitude_esperada).abs() < 1e-6);
+    }
+
+    #[test]
+    fn testa_ajustar_gradiente_converge_para_solucao_fechada() {
+        let valores_y = [2.0, 4.0, 6.0, 8.0, 10.0];
+        let periodos_x: Vec<f64> = (0..valores_y.len()).map(|v| v as f64).collect();
+
+        let (modelo, historico_mse) = RegressaoLinear::ajustar_gradiente(&periodos_x, &valores_y, 0.5, 2000).unwrap();
+
+        assert!((modelo.inclinacao - 2.0).abs() < 1e-3);
+        assert!((modelo.intercepto - 2.0).abs() < 1e-3);
+        assert_eq!(historico_mse.len(), 2000);
+        // O MSE deve diminuir ao longo do treino.
+        assert!(historico_mse.last().unwrap() < &historico_mse[0]);
+    }
+
+    #[test]
+    fn testa_ajustar_gradiente_vetores_vazios() {
+        let resultado = RegressaoLinear::ajustar_gradiente(&[], &[], 0.1, 100);
+        assert!(resultado.is_err());
+    }
+
+    #[test]
+    fn testa_ajustar_com_muitos_pontos() {
+        // Série longa o bastante para exercitar a soma compensada de Kahan-Neumaier
+        // nas reduções de `ajustar` e `r2` sem cair em cancelamento catastrófico.
+        let periodos_x: Vec<f64> = (0..2000).map(|v| v as f64).collect();
+        let valores_y: Vec<f64> = periodos_x.iter().map(|x| 10.0 + 3.0 * x).collect();
+
+        let modelo = RegressaoLinear::ajustar(&periodos_x, &valores_y).unwrap();
+
+        assert!((modelo.inclinacao - 3.0).abs() < 1e-6);
+        assert!((modelo.intercepto - 10.0).abs() < 1e-6);
+        let r2 = modelo.r2(&periodos_x, &valores_y).unwrap();
+        assert!((r2 - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn testa_previsor_arma_proximo_da_extrapolacao_linear() {
+        // Pequeno ruído alternado ao redor da reta: o ARMA deve captar pouca
+        // autocorrelação e `prever_horizonte` deve ficar próximo da
+        // extrapolação puramente linear.
+        let periodos_x: Vec<f64> = (0..12).map(|v| v as f64).collect();
+        let valores_y: Vec<f64> = periodos_x.iter()
+            .enumerate()
+            .map(|(i, x)| 2.0 + 2.0 * x + if i % 2 == 0 { 0.05 } else { -0.05 })
+            .collect();
+
+        let modelo = RegressaoLinear::ajustar(&periodos_x, &valores_y).unwrap();
+        let previsor = modelo.ajustar_arma_residuos(&periodos_x, &valores_y, 1, 1).unwrap();
+
+        let previsoes = previsor.prever_horizonte(3);
+        assert_eq!(previsoes.len(), 3);
+        for (i, previsto) in previsoes.iter().enumerate() {
+            let periodo_futuro = periodos_x.len() as f64 + i as f64;
+            assert!((previsto - modelo.prever(periodo_futuro).unwrap()).abs() < 0.5);
+        }
+    }
+
+    #[test]
+    fn testa_previsor_arma_ordens_invalidas() {
+        let valores_y = [2.0, 4.0, 6.0];
+        let periodos_x = [0.0, 1.0, 2.0];
+
+        let modelo = RegressaoLinear::ajustar(&periodos_x, &valores_y).unwrap();
+        assert!(modelo.ajustar_arma_residuos(&periodos_x, &valores_y, 5, 0).is_err());
+    }
+
+    #[test]
+    fn testa_previsor_arma_rejeita_periodos_desigualmente_espacados() {
+        // O passo usado por `prever_horizonte` vem só do último intervalo;
+        // com espaçamento irregular isso extrapolaria silenciosamente no
+        // passo errado, então `ajustar_arma_residuos` deve recusar a entrada.
+        let periodos_x = [0.0, 1.0, 2.0, 4.0, 5.0];
+        let valores_y: Vec<f64> = periodos_x.iter().map(|x| 2.0 + 3.0 * x).collect();
+
+        let modelo = RegressaoLinear::ajustar(&periodos_x, &valores_y).unwrap();
+        assert!(modelo.ajustar_arma_residuos(&periodos_x, &valores_y, 1, 0).is_err());
+    }
+
+    #[test]
+    fn testa_parece_linear_serie_linear() {
+        let valores_y = [2.0, 4.0, 6.0, 8.0, 10.0, 12.0];
+        let periodos_x: Vec<f64> = (0..valores_y.len()).map(|v| v as f64).collect();
+
+        assert!(parece_linear(&periodos_x, &valores_y, 0.1));
+    }
+
+    #[test]
+    fn testa_parece_linear_serie_nao_linear() {
+        let periodos_x: Vec<f64> = (0..10).map(|v| v as f64).collect();
+        let valores_y: Vec<f64> = periodos_x.iter().map(|x| x * x).collect();
+
+        assert!(!parece_linear(&periodos_x, &valores_y, 0.1));
+    }
+
+    #[test]
+    fn testa_parece_linear_poucos_pontos() {
+        let periodos_x = [0.0, 1.0];
+        let valores_y = [1.0, 2.0];
+
+        assert!(!parece_linear(&periodos_x, &valores_y, 0.1));
+    }
+
+    #[test]
+    fn testa_parece_linear_serie_saturante_nao_passa_so_pela_cauda() {
+        // y = 20·ln(x+1): a cauda se aplaina e, isoladamente, se ajusta bem a
+        // uma reta — mas a série inteira não é linear (RMSE do ajuste cheio
+        // é muito maior que a tolerância). O descarte de prefixo não pode
+        // "esconder" essa curvatura eliminando a maior parte dos pontos.
+        let periodos_x: Vec<f64> = (0..60).map(|v| v as f64).collect();
+        let valores_y: Vec<f64> = periodos_x.iter().map(|x| 20.0 * (x + 1.0).ln()).collect();
+
+        assert!(!parece_linear(&periodos_x, &valores_y, 0.3));
+    }
+
+    #[test]
+    fn testa_expoente_hurst_tendencia_forte() {
+        // Série com tendência linear forte: esperado H claramente acima de 0.5.
+        let serie: Vec<f64> = (0..64).map(|v| v as f64).collect();
+
+        let h = expoente_hurst(&serie).unwrap();
+        assert!(h > 0.6, "esperado H > 0.6 para série com tendência, obtido {h}");
+    }
+
+    #[test]
+    fn testa_expoente_hurst_poucos_pontos() {
+        let serie = [1.0, 2.0, 3.0];
+        assert!(expoente_hurst(&serie).is_err());
+    }
 }